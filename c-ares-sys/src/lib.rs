@@ -0,0 +1,273 @@
+//! Raw FFI bindings to the subset of libc-ares that the `c_ares` crate
+//! builds on.
+#![allow(non_camel_case_types)]
+
+use std::os::raw::{
+    c_char,
+    c_int,
+    c_uchar,
+    c_uint,
+    c_ushort,
+    c_void,
+};
+
+pub const ARES_SUCCESS: c_int = 0;
+pub const ARES_ENODATA: c_int = 1;
+pub const ARES_EFORMERR: c_int = 2;
+pub const ARES_ESERVFAIL: c_int = 3;
+pub const ARES_ENOTFOUND: c_int = 4;
+pub const ARES_ENOTIMP: c_int = 5;
+pub const ARES_EREFUSED: c_int = 6;
+pub const ARES_EBADQUERY: c_int = 7;
+pub const ARES_EBADNAME: c_int = 8;
+pub const ARES_EBADFAMILY: c_int = 9;
+pub const ARES_EBADRESP: c_int = 10;
+pub const ARES_ECONNREFUSED: c_int = 11;
+pub const ARES_ETIMEOUT: c_int = 12;
+pub const ARES_EOF: c_int = 13;
+pub const ARES_EFILE: c_int = 14;
+pub const ARES_ENOMEM: c_int = 15;
+pub const ARES_EDESTRUCTION: c_int = 16;
+pub const ARES_EBADSTR: c_int = 17;
+pub const ARES_EBADFLAGS: c_int = 18;
+pub const ARES_ENONAME: c_int = 19;
+pub const ARES_EBADHINTS: c_int = 20;
+pub const ARES_ENOTINITIALIZED: c_int = 21;
+pub const ARES_ELOADIPHLPAPI: c_int = 22;
+pub const ARES_EADDRGETNETWORKPARAMS: c_int = 23;
+pub const ARES_ECANCELLED: c_int = 24;
+pub const ARES_ESERVICE: c_int = 25;
+pub const ARES_ENOSERVER: c_int = 26;
+
+pub const ARES_OPT_FLAGS: c_int = 1 << 0;
+pub const ARES_OPT_TIMEOUT: c_int = 1 << 1;
+pub const ARES_OPT_TRIES: c_int = 1 << 2;
+pub const ARES_OPT_TIMEOUTMS: c_int = 1 << 13;
+pub const ARES_OPT_EDNSPSZ: c_int = 1 << 15;
+
+pub const ARES_SOCKET_BAD: c_int = -1;
+
+pub const ARES_SECTION_ANSWER: c_int = 1;
+pub const ARES_SECTION_ADDITIONAL: c_int = 3;
+
+pub const ARES_REC_TYPE_TLSA: c_int = 52;
+pub const ARES_RR_TLSA_CERT_USAGE: c_int = ARES_REC_TYPE_TLSA * 100 + 1;
+pub const ARES_RR_TLSA_SELECTOR: c_int = ARES_REC_TYPE_TLSA * 100 + 2;
+pub const ARES_RR_TLSA_MATCH: c_int = ARES_REC_TYPE_TLSA * 100 + 3;
+pub const ARES_RR_TLSA_DATA: c_int = ARES_REC_TYPE_TLSA * 100 + 4;
+
+pub const ARES_REC_TYPE_OPT: c_int = 41;
+pub const ARES_RR_OPT_FLAGS: c_int = ARES_REC_TYPE_OPT * 100 + 4;
+
+/// The DNSSEC OK (DO) bit - RFC 6891 section 6.1.4 - within an OPT
+/// pseudo-record's extended flags field (`ARES_RR_OPT_FLAGS`). Setting this
+/// on an outgoing query asks the server to include DNSSEC records, which is
+/// what makes the AD bit on the response trustworthy.
+pub const ARES_RR_OPT_FLAGS_DO: c_ushort = 1 << 15;
+
+pub const ARES_RR_OPT_OPTIONS: c_int = ARES_REC_TYPE_OPT * 100 + 5;
+
+/// RFC 7873 EDNS Cookie option, attached to an OPT RR's options list via
+/// `ares_dns_rr_set_opt(..., ARES_RR_OPT_OPTIONS, ARES_OPT_PARAM_COOKIE, ...)`.
+pub const ARES_OPT_PARAM_COOKIE: c_ushort = 10;
+
+pub const ARES_CLASS_IN: c_int = 1;
+pub const ARES_OPCODE_QUERY: c_int = 0;
+pub const ARES_RCODE_NOERROR: c_int = 0;
+
+/// RFC 2065 Authentic Data bit, as returned by `ares_dns_record_get_flags`.
+pub const ARES_FLAG_AD: c_ushort = 1 << 5;
+
+// `ares_socket_t` is `int` (a unix fd) on unix, but `SOCKET` - an
+// unsigned, pointer-sized handle - on Windows.
+#[cfg(unix)]
+pub type ares_socket_t = c_int;
+#[cfg(windows)]
+pub type ares_socket_t = usize;
+
+pub type ares_status_t = c_int;
+
+pub type ares_callback = unsafe extern "C" fn(
+    arg: *mut c_void,
+    status: c_int,
+    timeouts: c_int,
+    abuf: *mut c_uchar,
+    alen: c_int,
+);
+
+pub type ares_callback_dnsrec = unsafe extern "C" fn(
+    arg: *mut c_void,
+    status: ares_status_t,
+    timeouts: usize,
+    dnsrec: *const ares_dns_record_t,
+);
+
+pub enum Struct_ares_channeldata {}
+pub type ares_channel = *mut Struct_ares_channeldata;
+
+pub enum Struct_ares_dns_record {}
+pub type ares_dns_record_t = Struct_ares_dns_record;
+
+pub enum Struct_ares_dns_rr {}
+pub type ares_dns_rr_t = Struct_ares_dns_rr;
+
+#[repr(C)]
+pub struct Struct_in_addr {
+    pub s_addr: u32,
+}
+
+#[repr(C)]
+pub struct Struct_ares_addrttl {
+    pub ipaddr: Struct_in_addr,
+    pub ttl: c_int,
+}
+
+#[repr(C)]
+pub struct Struct_ares_srv_reply {
+    pub next: *mut Struct_ares_srv_reply,
+    pub host: *mut c_char,
+    pub priority: c_ushort,
+    pub weight: c_ushort,
+    pub port: c_ushort,
+}
+
+#[repr(C)]
+pub struct Struct_ares_server_failover_options {
+    pub retry_chance: c_ushort,
+    pub retry_delay: usize,
+}
+
+#[repr(C)]
+pub struct Struct_ares_options {
+    pub flags: c_int,
+    pub timeout: c_int,
+    pub tries: c_int,
+    pub ndots: c_int,
+    pub udp_port: c_ushort,
+    pub tcp_port: c_ushort,
+    pub socket_send_buffer_size: c_int,
+    pub socket_receive_buffer_size: c_int,
+    pub servers: *mut Struct_in_addr,
+    pub nservers: c_int,
+    pub domains: *mut *mut c_char,
+    pub ndomains: c_int,
+    pub lookups: *mut c_char,
+    pub sock_state_cb:
+        Option<unsafe extern "C" fn(*mut c_void, ares_socket_t, c_int, c_int)>,
+    pub sock_state_cb_data: *mut c_void,
+    pub sortlist: *mut c_void,
+    pub nsort: c_int,
+    pub ednspsz: c_int,
+    pub resolvconf_path: *mut c_char,
+    pub hosts_path: *mut c_char,
+    pub udp_max_queries: c_int,
+    pub maxtimeout: c_int,
+    pub qcache_max_ttl: c_uint,
+    pub evsys: c_int,
+    pub server_failover_opts: Struct_ares_server_failover_options,
+}
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub struct Struct_timeval {
+    pub tv_sec: i64,
+    pub tv_usec: i64,
+}
+
+extern "C" {
+    pub fn ares_init_options(
+        channelptr: *mut ares_channel,
+        options: *const Struct_ares_options,
+        optmask: c_int) -> c_int;
+    pub fn ares_destroy(channel: ares_channel);
+    pub fn ares_reinit(channel: ares_channel) -> c_int;
+    pub fn ares_set_servers_csv(channel: ares_channel, servers: *const c_char) -> c_int;
+    pub fn ares_get_servers_csv(channel: ares_channel) -> *mut c_char;
+    pub fn ares_set_local_ip4(channel: ares_channel, local_ip: c_uint);
+    pub fn ares_set_local_dev(channel: ares_channel, local_dev_name: *const c_char);
+
+    pub fn ares_getsock(
+        channel: ares_channel,
+        socks: *mut ares_socket_t,
+        numsocks: c_int) -> c_int;
+    pub fn ares_timeout(
+        channel: ares_channel,
+        maxtv: *mut Struct_timeval,
+        tv: *mut Struct_timeval) -> *mut Struct_timeval;
+    pub fn ares_process_fd(
+        channel: ares_channel,
+        read_fd: ares_socket_t,
+        write_fd: ares_socket_t);
+
+    pub fn ares_query(
+        channel: ares_channel,
+        name: *const c_char,
+        dnsclass: c_int,
+        dtype: c_int,
+        callback: ares_callback,
+        arg: *mut c_void);
+
+    pub fn ares_parse_a_reply(
+        abuf: *const c_uchar,
+        alen: c_int,
+        host: *mut *mut c_void,
+        addrttls: *mut Struct_ares_addrttl,
+        naddrttls: *mut c_int) -> c_int;
+    pub fn ares_parse_srv_reply(
+        abuf: *const c_uchar,
+        alen: c_int,
+        srv_out: *mut *mut Struct_ares_srv_reply) -> c_int;
+
+    pub fn ares_free_data(dataptr: *mut c_void);
+    pub fn ares_free_string(str_: *mut c_void);
+
+    pub fn ares_dns_record_destroy(dnsrec: *mut ares_dns_record_t);
+    pub fn ares_dns_record_get_flags(dnsrec: *const ares_dns_record_t) -> c_ushort;
+    pub fn ares_dns_record_rr_cnt(dnsrec: *const ares_dns_record_t, sect: c_int) -> usize;
+    pub fn ares_dns_record_rr_get_const(
+        dnsrec: *const ares_dns_record_t,
+        sect: c_int,
+        idx: usize) -> *const ares_dns_rr_t;
+    pub fn ares_dns_rr_get_u8(dns_rr: *const ares_dns_rr_t, key: c_int) -> c_uchar;
+    pub fn ares_dns_rr_get_bin(
+        dns_rr: *const ares_dns_rr_t,
+        key: c_int,
+        len: *mut usize) -> *const c_uchar;
+
+    pub fn ares_dns_record_create(
+        dnsrec: *mut *mut ares_dns_record_t,
+        id: c_ushort,
+        flags: c_ushort,
+        opcode: c_int,
+        rcode: c_int) -> ares_status_t;
+    pub fn ares_dns_record_query_add(
+        dnsrec: *mut ares_dns_record_t,
+        name: *const c_char,
+        qtype: c_int,
+        qclass: c_int) -> ares_status_t;
+    pub fn ares_dns_record_rr_add(
+        rr_out: *mut *mut ares_dns_rr_t,
+        dnsrec: *mut ares_dns_record_t,
+        sect: c_int,
+        name: *const c_char,
+        rtype: c_int,
+        rclass: c_int,
+        ttl: c_uint) -> ares_status_t;
+    pub fn ares_dns_rr_set_u16(
+        dns_rr: *mut ares_dns_rr_t,
+        key: c_int,
+        val: c_ushort) -> ares_status_t;
+    pub fn ares_dns_rr_set_opt(
+        dns_rr: *mut ares_dns_rr_t,
+        key: c_int,
+        opt: c_ushort,
+        val: *const c_uchar,
+        val_len: usize) -> ares_status_t;
+
+    pub fn ares_send_dnsrec(
+        channel: ares_channel,
+        dnsrec: *const ares_dns_record_t,
+        callback: ares_callback_dnsrec,
+        arg: *mut c_void,
+        qid: *mut c_ushort) -> ares_status_t;
+}
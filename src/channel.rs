@@ -0,0 +1,317 @@
+extern crate c_ares_sys;
+
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_int, c_void};
+#[cfg(unix)]
+use std::os::unix::io::RawFd;
+#[cfg(windows)]
+use std::os::windows::io::RawSocket;
+use std::ptr;
+use std::time::Duration;
+
+use a::{query_a_callback, AResults};
+use edns::random_client_cookie;
+use error::AresError;
+use options::Options;
+use socket::{Socket, SocketReadiness};
+use tlsa::{query_tlsa_callback, TLSAResults};
+use utils::ares_error;
+
+// DNS class and type values that `ares_query` expects - see `ares_nameser.h`.
+const C_IN: c_int = 1;
+const T_A: c_int = 1;
+const T_TLSA: c_int = c_ares_sys::ARES_REC_TYPE_TLSA;
+
+/// The maximum number of sockets that `ares_getsock()` will report on.
+const ARES_GETSOCK_MAXNUM: usize = 16;
+
+/// A channel for making DNS requests.
+pub struct Channel {
+    raw: c_ares_sys::ares_channel,
+    dns_cookies: bool,
+}
+
+// The raw channel is only ever accessed through `&mut self`, so it's safe to
+// move between threads.
+unsafe impl Send for Channel {}
+
+impl Channel {
+    /// Create a new `Channel`, configured according to `options`.
+    pub fn new(options: Options) -> Result<Channel, AresError> {
+        let mut raw = ptr::null_mut();
+        let result = unsafe {
+            c_ares_sys::ares_init_options(
+                &mut raw,
+                options.ares_options(),
+                options.optmask())
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            Err(ares_error(result))
+        } else {
+            Ok(Channel {
+                raw,
+                dns_cookies: options.dns_cookies(),
+            })
+        }
+    }
+
+    /// Returns the raw `ares_channel` that this `Channel` wraps, for use by
+    /// other modules in this crate.
+    pub(crate) fn ares_channel(&mut self) -> c_ares_sys::ares_channel {
+        self.raw
+    }
+
+    /// Returns this channel's current nameserver list, in the same
+    /// comma-separated form accepted by `set_servers_csv()`.
+    pub fn get_servers_csv(&mut self) -> Result<String, AresError> {
+        let raw = unsafe { c_ares_sys::ares_get_servers_csv(self.raw) };
+        if raw.is_null() {
+            return Err(ares_error(c_ares_sys::ARES_ENOMEM));
+        }
+        let csv = unsafe { CStr::from_ptr(raw) }.to_string_lossy().into_owned();
+        unsafe {
+            c_ares_sys::ares_free_string(raw as *mut c_void);
+        }
+        Ok(csv)
+    }
+
+    /// Look up the A records for `name`.
+    ///
+    /// Returns `Err(AresError::EBADNAME)` without issuing a query if `name`
+    /// contains an embedded NUL byte.
+    pub fn query_a<F>(&mut self, name: &str, handler: F) -> Result<(), AresError>
+        where F: FnOnce(Result<AResults, AresError>) + 'static {
+        let c_name = CString::new(name).map_err(|_| AresError::EBADNAME)?;
+        let handler = Box::new(handler);
+        unsafe {
+            c_ares_sys::ares_query(
+                self.raw,
+                c_name.as_ptr(),
+                C_IN,
+                T_A,
+                query_a_callback::<F>,
+                Box::into_raw(handler) as *mut c_void);
+        }
+        Ok(())
+    }
+
+    /// Returns the sockets that this channel would like to be notified
+    /// about, as `(fd, readable, writable)` triples - mirroring
+    /// `ares_getsock()`.
+    #[cfg(unix)]
+    pub fn get_sock(&mut self) -> Vec<(RawFd, bool, bool)> {
+        let mut socks = [0 as c_ares_sys::ares_socket_t; ARES_GETSOCK_MAXNUM];
+        let bitmask = unsafe {
+            c_ares_sys::ares_getsock(
+                self.raw,
+                socks.as_mut_ptr(),
+                ARES_GETSOCK_MAXNUM as c_int)
+        };
+        let mut result = Vec::new();
+        for (index, &fd) in socks.iter().enumerate() {
+            let readable = bitmask & (1 << index) != 0;
+            let writable = bitmask & (1 << (index + ARES_GETSOCK_MAXNUM)) != 0;
+            if readable || writable {
+                result.push((fd as RawFd, readable, writable));
+            }
+        }
+        result
+    }
+
+    /// Returns the sockets that this channel would like to be notified
+    /// about, as `(socket, readable, writable)` triples - mirroring
+    /// `ares_getsock()`.
+    #[cfg(windows)]
+    pub fn get_sock(&mut self) -> Vec<(RawSocket, bool, bool)> {
+        let mut socks = [0 as c_ares_sys::ares_socket_t; ARES_GETSOCK_MAXNUM];
+        let bitmask = unsafe {
+            c_ares_sys::ares_getsock(
+                self.raw,
+                socks.as_mut_ptr(),
+                ARES_GETSOCK_MAXNUM as c_int)
+        };
+        let mut result = Vec::new();
+        for (index, &sock) in socks.iter().enumerate() {
+            let readable = bitmask & (1 << index) != 0;
+            let writable = bitmask & (1 << (index + ARES_GETSOCK_MAXNUM)) != 0;
+            if readable || writable {
+                result.push((sock as RawSocket, readable, writable));
+            }
+        }
+        result
+    }
+
+    /// Look up the TLSA records for `name`, for DANE (DNS-Based
+    /// Authentication of Named Entities) certificate pinning.
+    ///
+    /// There has never been an `ares_parse_tlsa_reply()` in c-ares, so -
+    /// unlike `query_a()` - this goes via the generic DNS-record API, and
+    /// sets the EDNS DO (DNSSEC OK) bit on the query so that a signed
+    /// response's AD bit, surfaced via `TLSAResults::is_authenticated()`,
+    /// means something. If `Options::set_dns_cookies()` was enabled, also
+    /// attaches an RFC 7873 client cookie, via the same OPT RR.
+    ///
+    /// Returns `Err(AresError::EBADNAME)` without issuing a query if `name`
+    /// contains an embedded NUL byte.
+    pub fn query_tlsa<F>(&mut self, name: &str, handler: F) -> Result<(), AresError>
+        where F: FnOnce(Result<TLSAResults, AresError>) + 'static {
+        let c_name = CString::new(name).map_err(|_| AresError::EBADNAME)?;
+        let mut dnsrec = ptr::null_mut();
+        let result = unsafe {
+            c_ares_sys::ares_dns_record_create(
+                &mut dnsrec,
+                0,
+                0,
+                c_ares_sys::ARES_OPCODE_QUERY,
+                c_ares_sys::ARES_RCODE_NOERROR)
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            return Err(ares_error(result));
+        }
+        let result = unsafe {
+            c_ares_sys::ares_dns_record_query_add(dnsrec, c_name.as_ptr(), T_TLSA, C_IN)
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            unsafe {
+                c_ares_sys::ares_dns_record_destroy(dnsrec);
+            }
+            return Err(ares_error(result));
+        }
+        let mut opt_rr = ptr::null_mut();
+        let result = unsafe {
+            c_ares_sys::ares_dns_record_rr_add(
+                &mut opt_rr,
+                dnsrec,
+                c_ares_sys::ARES_SECTION_ADDITIONAL,
+                b"\0".as_ptr() as *const _,
+                c_ares_sys::ARES_REC_TYPE_OPT,
+                c_ares_sys::ARES_CLASS_IN,
+                0)
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            unsafe {
+                c_ares_sys::ares_dns_record_destroy(dnsrec);
+            }
+            return Err(ares_error(result));
+        }
+        let result = unsafe {
+            c_ares_sys::ares_dns_rr_set_u16(
+                opt_rr,
+                c_ares_sys::ARES_RR_OPT_FLAGS,
+                c_ares_sys::ARES_RR_OPT_FLAGS_DO)
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            unsafe {
+                c_ares_sys::ares_dns_record_destroy(dnsrec);
+            }
+            return Err(ares_error(result));
+        }
+        if self.dns_cookies {
+            let cookie = random_client_cookie();
+            let result = unsafe {
+                c_ares_sys::ares_dns_rr_set_opt(
+                    opt_rr,
+                    c_ares_sys::ARES_RR_OPT_OPTIONS,
+                    c_ares_sys::ARES_OPT_PARAM_COOKIE,
+                    cookie.as_ptr(),
+                    cookie.len())
+            };
+            if result != c_ares_sys::ARES_SUCCESS {
+                unsafe {
+                    c_ares_sys::ares_dns_record_destroy(dnsrec);
+                }
+                return Err(ares_error(result));
+            }
+        }
+
+        let handler = Box::new(handler);
+        let result = unsafe {
+            c_ares_sys::ares_send_dnsrec(
+                self.raw,
+                dnsrec,
+                query_tlsa_callback::<F>,
+                Box::into_raw(handler) as *mut c_void,
+                ptr::null_mut())
+        };
+        unsafe {
+            c_ares_sys::ares_dns_record_destroy(dnsrec);
+        }
+        if result != c_ares_sys::ARES_SUCCESS {
+            return Err(ares_error(result));
+        }
+        Ok(())
+    }
+
+    /// Returns the sockets that this channel would like to be notified
+    /// about, paired with the readiness it's interested in for each -
+    /// mirroring `get_sock()`, but in terms of the portable `Socket` handle
+    /// that an event loop can register directly.
+    ///
+    /// Unlike the built-in `EventLoop`/`Resolver` (unix-only, since `mio`
+    /// 0.6 has no generic `Evented` source for a raw Windows `SOCKET`),
+    /// this works on every platform `Socket` supports - on Windows, a
+    /// caller can still read each handle back via `Socket::as_raw_socket()`
+    /// and drive its own IOCP-based event loop.
+    pub fn get_socket_readiness(&mut self) -> Vec<SocketReadiness> {
+        self.get_sock()
+            .into_iter()
+            .map(|(handle, readable, writable)| {
+                SocketReadiness::new(Socket::new(handle), readable, writable)
+            })
+            .collect()
+    }
+
+    /// Returns the amount of time to wait before the channel's next query
+    /// retry is due, or `None` if there's no pending query.
+    pub fn get_timeout(&mut self) -> Option<Duration> {
+        let mut tv = c_ares_sys::Struct_timeval {
+            tv_sec: 0,
+            tv_usec: 0,
+        };
+        let result = unsafe {
+            c_ares_sys::ares_timeout(self.raw, ptr::null_mut(), &mut tv)
+        };
+        if result.is_null() {
+            None
+        } else {
+            Some(Duration::new(tv.tv_sec as u64, (tv.tv_usec as u32) * 1_000))
+        }
+    }
+
+    /// Tell the channel that the given sockets are ready for reading and/or
+    /// writing - use `types::SOCKET_BAD` for a socket that isn't ready.
+    pub fn process_fd(&mut self, read_fd: c_int, write_fd: c_int) {
+        unsafe {
+            c_ares_sys::ares_process_fd(self.raw, read_fd, write_fd);
+        }
+    }
+}
+
+impl Drop for Channel {
+    fn drop(&mut self) {
+        unsafe {
+            c_ares_sys::ares_destroy(self.raw);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use channel::Channel;
+    use error::AresError;
+    use options::Options;
+
+    #[test]
+    fn query_a_rejects_embedded_nul_byte() {
+        let mut channel = Channel::new(Options::new()).unwrap();
+        let result = channel.query_a("exa\0mple.com", |_| {});
+        assert_eq!(result, Err(AresError::EBADNAME));
+    }
+
+    #[test]
+    fn query_tlsa_rejects_embedded_nul_byte() {
+        let mut channel = Channel::new(Options::new()).unwrap();
+        let result = channel.query_tlsa("exa\0mple.com", |_| {});
+        assert_eq!(result, Err(AresError::EBADNAME));
+    }
+}
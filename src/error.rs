@@ -0,0 +1,43 @@
+use std::error;
+use std::fmt;
+
+/// An error as returned by c-ares.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AresError {
+    ENODATA,
+    EFORMERR,
+    ESERVFAIL,
+    ENOTFOUND,
+    ENOTIMP,
+    EREFUSED,
+    EBADQUERY,
+    EBADNAME,
+    EBADFAMILY,
+    EBADRESP,
+    ECONNREFUSED,
+    ETIMEOUT,
+    EOF,
+    EFILE,
+    ENOMEM,
+    EDESTRUCTION,
+    EBADSTR,
+    EBADFLAGS,
+    ENONAME,
+    EBADHINTS,
+    ENOTINITIALIZED,
+    ELOADIPHLPAPI,
+    EADDRGETNETWORKPARAMS,
+    ECANCELLED,
+    ESERVICE,
+    ENOSERVER,
+    /// A status code that this crate doesn't otherwise know about.
+    Unknown(i32),
+}
+
+impl fmt::Display for AresError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{:?}", self)
+    }
+}
+
+impl error::Error for AresError {}
@@ -0,0 +1,23 @@
+extern crate c_ares_sys;
+
+mod a;
+mod channel;
+mod edns;
+mod error;
+mod options;
+mod reconfigure;
+mod resolver;
+mod socket;
+mod tlsa;
+mod types;
+mod utils;
+
+pub use a::{AResult, AResults, AResultsIter};
+pub use channel::Channel;
+pub use error::AresError;
+pub use options::Options;
+pub use resolver::{AresFuture, FutureResolver};
+#[cfg(unix)]
+pub use resolver::Resolver;
+pub use socket::{Socket, SocketReadiness};
+pub use tlsa::{TLSAResult, TLSAResults, TLSAResultsIter};
@@ -0,0 +1,82 @@
+extern crate c_ares_sys;
+
+use std::mem;
+use std::os::raw::c_int;
+
+/// Configuration for creating a `Channel`.
+pub struct Options {
+    ares_options: c_ares_sys::Struct_ares_options,
+    optmask: c_int,
+    dns_cookies: bool,
+}
+
+impl Default for Options {
+    fn default() -> Options {
+        Options::new()
+    }
+}
+
+impl Options {
+    /// Create a new, empty, set of `Options`.
+    pub fn new() -> Options {
+        Options {
+            ares_options: unsafe { mem::zeroed() },
+            optmask: 0,
+            dns_cookies: false,
+        }
+    }
+
+    /// Apply `f` to this `Options`'s raw `ares_options`, and record that the
+    /// corresponding `mask` bit should be passed to `ares_init_options()`.
+    pub(crate) fn set_opt<F>(&mut self, mask: c_int, f: F) -> &mut Options
+        where F: FnOnce(&mut c_ares_sys::Struct_ares_options) {
+        f(&mut self.ares_options);
+        self.optmask |= mask;
+        self
+    }
+
+    /// Set flags controlling the behaviour of the resolver - see
+    /// `ares_init_options()`.
+    pub fn set_flags(&mut self, flags: c_int) -> &mut Options {
+        self.set_opt(c_ares_sys::ARES_OPT_FLAGS, |options| {
+            options.flags = flags;
+        })
+    }
+
+    /// Set the number of milliseconds to wait for a response before
+    /// retrying a query.
+    pub fn set_timeout(&mut self, timeout_ms: u32) -> &mut Options {
+        self.set_opt(c_ares_sys::ARES_OPT_TIMEOUTMS, |options| {
+            options.timeout = timeout_ms as c_int;
+        })
+    }
+
+    /// Set the number of tries the resolver will make before giving up.
+    pub fn set_tries(&mut self, tries: u32) -> &mut Options {
+        self.set_opt(c_ares_sys::ARES_OPT_TRIES, |options| {
+            options.tries = tries as c_int;
+        })
+    }
+
+    /// Returns the raw `ares_options`, for passing to `ares_init_options()`.
+    pub(crate) fn ares_options(&self) -> &c_ares_sys::Struct_ares_options {
+        &self.ares_options
+    }
+
+    /// Returns the option mask, for passing to `ares_init_options()`.
+    pub(crate) fn optmask(&self) -> c_int {
+        self.optmask
+    }
+
+    /// Set whether queries built via the generic DNS-record API should
+    /// attach an RFC 7873 client cookie - see `Options::set_dns_cookies()`.
+    pub(crate) fn set_dns_cookies_flag(&mut self, enable: bool) {
+        self.dns_cookies = enable;
+    }
+
+    /// Returns whether `set_dns_cookies()` was enabled, for `Channel::new()`
+    /// to carry forward onto the `Channel` itself.
+    pub(crate) fn dns_cookies(&self) -> bool {
+        self.dns_cookies
+    }
+}
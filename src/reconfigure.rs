@@ -0,0 +1,115 @@
+extern crate c_ares_sys;
+
+use std::ffi::CString;
+use std::fs;
+use std::net::SocketAddr;
+
+use channel::Channel;
+use error::AresError;
+use utils::ares_error;
+
+/// The well-known path that `ares_reinit()` would otherwise have read.
+const RESOLV_CONF_PATH: &str = "/etc/resolv.conf";
+
+/// Pull `nameserver` addresses out of resolv.conf-style file contents, in
+/// the order they appear.
+fn parse_nameservers(contents: &str) -> Vec<&str> {
+    contents
+        .lines()
+        .filter_map(|line| {
+            let mut words = line.split_whitespace();
+            if words.next() == Some("nameserver") {
+                words.next()
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+/// Methods for reconfiguring an already-running `Channel` in place, so
+/// that a long-lived resolver (for example in a daemon) can pick up
+/// network changes without losing in-flight queries and sockets.
+impl Channel {
+    /// Replace this channel's nameservers with `servers`, taking effect
+    /// immediately. In-flight queries continue to be tracked against the
+    /// channel's sockets, and are retried against the new servers if they
+    /// haven't yet completed.
+    pub fn set_servers(&mut self, servers: &[SocketAddr]) -> Result<&mut Channel, AresError> {
+        let csv = servers
+            .iter()
+            .map(SocketAddr::to_string)
+            .collect::<Vec<_>>()
+            .join(",");
+        let c_servers = CString::new(csv).unwrap();
+        let result = unsafe {
+            c_ares_sys::ares_set_servers_csv(self.ares_channel(), c_servers.as_ptr())
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            Err(ares_error(result))
+        } else {
+            Ok(self)
+        }
+    }
+
+    /// Set the local IPv4 address from which queries are made, overriding
+    /// any previous choice.
+    pub fn set_local_ip(&mut self, addr: [u8; 4]) -> &mut Channel {
+        let packed = u32::from_be_bytes(addr);
+        unsafe {
+            c_ares_sys::ares_set_local_ip4(self.ares_channel(), packed);
+        }
+        self
+    }
+
+    /// Re-read the system's resolver configuration (`/etc/resolv.conf`),
+    /// replacing this channel's nameserver list with whatever is
+    /// configured there now.
+    ///
+    /// This reads and parses the file itself and calls
+    /// `set_servers_csv()` with the result, rather than asking
+    /// `ares_reinit()` to pick the system config back up: per its own
+    /// documented contract, `ares_reinit()` never overrides servers that
+    /// were set explicitly - and an empty list set via
+    /// `ares_set_servers_csv(channel, NULL)` counts as exactly that, so
+    /// that approach left the channel with zero servers instead of
+    /// resolv.conf's.
+    pub fn reload_resolv_conf(&mut self) -> Result<&mut Channel, AresError> {
+        let contents = fs::read_to_string(RESOLV_CONF_PATH).map_err(|_| AresError::EFILE)?;
+        let servers = parse_nameservers(&contents);
+        if servers.is_empty() {
+            return Err(AresError::ENODATA);
+        }
+        let csv = servers.join(",");
+        let c_servers = CString::new(csv).map_err(|_| AresError::EBADSTR)?;
+        let result = unsafe {
+            c_ares_sys::ares_set_servers_csv(self.ares_channel(), c_servers.as_ptr())
+        };
+        if result != c_ares_sys::ARES_SUCCESS {
+            Err(ares_error(result))
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::parse_nameservers;
+    use channel::Channel;
+    use options::Options;
+
+    #[test]
+    fn parse_nameservers_extracts_server_lines() {
+        let contents = "nameserver 1.2.3.4\noptions ndots:5\nnameserver 5.6.7.8\n";
+        assert_eq!(parse_nameservers(contents), vec!["1.2.3.4", "5.6.7.8"]);
+    }
+
+    #[test]
+    fn reload_resolv_conf_populates_server_list_from_the_real_file() {
+        let mut channel = Channel::new(Options::new()).unwrap();
+        channel.reload_resolv_conf().unwrap();
+        let servers = channel.get_servers_csv().unwrap();
+        assert!(!servers.is_empty());
+    }
+}
@@ -0,0 +1,156 @@
+extern crate mio;
+
+use std::collections::{HashMap, HashSet};
+use std::os::unix::io::{AsRawFd, RawFd};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::thread::JoinHandle;
+
+use self::mio::unix::EventedFd;
+use self::mio::{Events, Poll, PollOpt, Ready, Registration, SetReadiness, Token};
+
+use channel::Channel;
+use types::SOCKET_BAD;
+
+/// Token for the `Registration` that lets `notify()` wake the poll loop
+/// early - distinct from any fd-backed token, which are allocated from 0.
+///
+/// `Token(usize::MAX)` itself is reserved by `mio::Poll` for its own
+/// internal wakeup bookkeeping, and registering it is rejected outright -
+/// so this uses the next value down instead.
+const WAKE_TOKEN: Token = Token(usize::MAX - 1);
+
+/// Drives a `Channel`'s sockets on a background thread, so that callers
+/// don't have to hand-roll an event loop of their own.
+pub struct EventLoop {
+    set_readiness: SetReadiness,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl EventLoop {
+    /// Spawn a thread that polls `channel` until this `EventLoop` is
+    /// dropped.
+    pub fn spawn(channel: Arc<Mutex<Channel>>) -> EventLoop {
+        let poll = Poll::new().expect("failed to create mio::Poll");
+        let (registration, set_readiness) = Registration::new2();
+        poll.register(
+            &registration,
+            WAKE_TOKEN,
+            Ready::readable(),
+            PollOpt::edge()).expect("failed to register wake source");
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = shutdown.clone();
+        let handle = thread::spawn(move || {
+            run(channel, poll, registration, thread_shutdown);
+        });
+        EventLoop {
+            set_readiness,
+            shutdown,
+            handle: Some(handle),
+        }
+    }
+
+    /// Wake the background thread, so that it re-reads
+    /// `get_socket_readiness()` - for example because a new query has just
+    /// opened a fresh socket.
+    pub fn notify(&self) {
+        let _ = self.set_readiness.set_readiness(Ready::readable());
+    }
+}
+
+impl Drop for EventLoop {
+    fn drop(&mut self) {
+        self.shutdown.store(true, Ordering::SeqCst);
+        self.notify();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+fn run(
+    channel: Arc<Mutex<Channel>>,
+    poll: Poll,
+    // Kept alive for the loop's duration: `mio::Registration` deregisters
+    // itself on drop, which would make `notify()` unable to wake `poll`.
+    _registration: Registration,
+    shutdown: Arc<AtomicBool>) {
+    let mut events = Events::with_capacity(16);
+    let mut tokens: HashMap<RawFd, Token> = HashMap::new();
+    let mut next_token = 0usize;
+
+    loop {
+        if shutdown.load(Ordering::SeqCst) {
+            return;
+        }
+
+        let timeout = {
+            let mut locked = channel.lock().unwrap();
+            let mut seen = HashSet::with_capacity(tokens.len());
+            for readiness in locked.get_socket_readiness() {
+                let fd = readiness.socket().as_raw_fd();
+                let interest = match readiness.interest() {
+                    Some(interest) => interest,
+                    None => continue,
+                };
+                seen.insert(fd);
+                match tokens.get(&fd).cloned() {
+                    Some(token) => {
+                        let _ = readiness.socket().reregister(&poll, token, interest);
+                    }
+                    None => {
+                        let token = Token(next_token);
+                        next_token += 1;
+                        tokens.insert(fd, token);
+                        let _ = readiness.socket().register(&poll, token, interest);
+                    }
+                }
+            }
+
+            // Drop any fd that's no longer among the channel's live sockets
+            // (for example a UDP socket that was closed after a retry) - if
+            // left in `tokens`, it would leak forever in a long-running
+            // daemon, one stale entry per socket churn.
+            let stale: Vec<RawFd> = tokens
+                .keys()
+                .cloned()
+                .filter(|fd| !seen.contains(fd))
+                .collect();
+            for fd in stale {
+                tokens.remove(&fd);
+                let _ = poll.deregister(&EventedFd(&fd));
+            }
+
+            locked.get_timeout()
+        };
+
+        // Block until either a socket is ready, the channel's own retry
+        // timeout expires, or `notify()` wakes us early - whichever comes
+        // first. This is what makes responses actually get processed as
+        // soon as they arrive, rather than only on the next retry tick.
+        let _ = poll.poll(&mut events, timeout);
+
+        let mut locked = channel.lock().unwrap();
+        let mut saw_socket_event = false;
+        for event in &events {
+            if event.token() == WAKE_TOKEN {
+                continue;
+            }
+            let fd = match tokens.iter().find(|&(_, &token)| token == event.token()) {
+                Some((&fd, _)) => fd,
+                None => continue,
+            };
+            saw_socket_event = true;
+            let readiness = event.readiness();
+            let read_fd = if readiness.is_readable() { fd } else { SOCKET_BAD };
+            let write_fd = if readiness.is_writable() { fd } else { SOCKET_BAD };
+            locked.process_fd(read_fd, write_fd);
+        }
+        if !saw_socket_event {
+            locked.process_fd(SOCKET_BAD, SOCKET_BAD);
+        }
+    }
+}
@@ -0,0 +1,71 @@
+extern crate futures;
+
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use self::futures::channel::oneshot;
+
+use a::AResults;
+use channel::Channel;
+use error::AresError;
+
+/// A `Future` that resolves to the result of a single query, fulfilled by
+/// the `Channel` callback that the query was issued with.
+pub struct AresFuture<T> {
+    receiver: oneshot::Receiver<Result<T, AresError>>,
+}
+
+impl<T> Future for AresFuture<T> {
+    type Output = Result<T, AresError>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        // `oneshot::Receiver` is `Unpin`, so there's no need for unsafe
+        // pin-projection here.
+        let receiver = &mut self.get_mut().receiver;
+        match Pin::new(receiver).poll(cx) {
+            Poll::Ready(Ok(result)) => Poll::Ready(result),
+
+            // The sender was dropped without ever being fulfilled. A
+            // destroyed `Channel` doesn't end up here: `ares_destroy()`
+            // completes each pending query's callback with
+            // `ARES_EDESTRUCTION`, which arrives as `Err(EDESTRUCTION)`.
+            Poll::Ready(Err(_canceled)) => Poll::Ready(Err(AresError::ECANCELLED)),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
+
+/// A resolver that turns each query into a `Future`, instead of requiring
+/// the caller to supply a callback.
+///
+/// Something still needs to drive the underlying `Channel` - by calling
+/// `get_sock()` and `process_fd()` as usual - in order for these futures
+/// to make progress.
+pub struct FutureResolver {
+    channel: Channel,
+}
+
+impl FutureResolver {
+    /// Create a new `FutureResolver`, wrapping the given `Channel`.
+    pub fn new(channel: Channel) -> FutureResolver {
+        FutureResolver { channel }
+    }
+
+    /// Returns a reference to the wrapped `Channel`, for driving its event
+    /// loop.
+    pub fn channel(&mut self) -> &mut Channel {
+        &mut self.channel
+    }
+
+    /// Look up the A records for `name`.
+    ///
+    /// Returns `Err(AresError::EBADNAME)` without issuing a query if `name`
+    /// contains an embedded NUL byte.
+    pub fn query_a(&mut self, name: &str) -> Result<AresFuture<AResults>, AresError> {
+        let (sender, receiver) = oneshot::channel();
+        self.channel.query_a(name, move |result| {
+            let _ = sender.send(result);
+        })?;
+        Ok(AresFuture { receiver })
+    }
+}
@@ -0,0 +1,11 @@
+//! A higher-level resolver built on top of `Channel`'s callback API.
+
+mod future;
+#[cfg(unix)]
+mod event_loop;
+#[cfg(unix)]
+mod sync;
+
+pub use self::future::{AresFuture, FutureResolver};
+#[cfg(unix)]
+pub use self::sync::Resolver;
@@ -0,0 +1,59 @@
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+
+use a::AResults;
+use channel::Channel;
+use error::AresError;
+use resolver::event_loop::EventLoop;
+
+/// A blocking resolver that drives its own background event loop, so that
+/// callers just get a `Result` back without touching `get_sock()` or
+/// `process_fd()` themselves.
+pub struct Resolver {
+    channel: Arc<Mutex<Channel>>,
+    event_loop: EventLoop,
+}
+
+impl Resolver {
+    /// Create a new `Resolver`, wrapping the given `Channel`.
+    pub fn new(channel: Channel) -> Resolver {
+        let channel = Arc::new(Mutex::new(channel));
+        let event_loop = EventLoop::spawn(channel.clone());
+        Resolver { channel, event_loop }
+    }
+
+    /// Look up the A records for `name`, blocking until the answer (or an
+    /// error) arrives.
+    pub fn query_a(&self, name: &str) -> Result<AResults, AresError> {
+        let (sender, receiver) = mpsc::channel();
+        self.channel.lock().unwrap().query_a(name, move |result| {
+            let _ = sender.send(result);
+        })?;
+        self.event_loop.notify();
+        receiver.recv().expect("event loop thread died")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use channel::Channel;
+    use error::AresError;
+    use options::Options;
+    use resolver::Resolver;
+
+    #[test]
+    fn query_a_drives_the_background_event_loop_to_completion() {
+        // Bound how long an unanswered query can take, as long as the event
+        // loop processes timeouts. The name needn't resolve - NXDOMAIN and
+        // NODATA answers count as completion too - but a nameserver has to
+        // answer: a timeout or refused connection fails the test.
+        let mut options = Options::new();
+        options.set_timeout(2000).set_tries(2);
+        let channel = Channel::new(options).unwrap();
+        let resolver = Resolver::new(channel);
+        match resolver.query_a("example.com") {
+            Ok(_) | Err(AresError::ENOTFOUND) | Err(AresError::ENODATA) => {}
+            Err(e) => panic!("unexpected error from query_a: {:?}", e),
+        }
+    }
+}
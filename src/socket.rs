@@ -0,0 +1,127 @@
+#[cfg(unix)]
+extern crate mio;
+
+use std::io;
+
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, RawFd};
+#[cfg(windows)]
+use std::os::windows::io::{AsRawSocket, RawSocket};
+
+#[cfg(unix)]
+use self::mio::unix::EventedFd;
+#[cfg(unix)]
+use self::mio::{Poll, PollOpt, Ready, Token};
+
+/// A socket handle yielded by `Channel::get_socket_readiness()`.
+///
+/// This wraps the platform's native socket representation - a `RawFd` on
+/// unix, a `RawSocket` on Windows - so that callers can write a single
+/// event-loop integration instead of `#[cfg]`-ing their way through `RawFd`
+/// by hand.
+///
+/// Registering a `Socket` with an event loop (`register`/`reregister`/
+/// `deregister`) is currently only implemented on unix, where `mio`'s
+/// `EventedFd` gives a ready-made `Evented` impl for a raw descriptor. `mio`
+/// 0.6 has no equivalent generic wrapper for a raw Windows `SOCKET`, so on
+/// Windows callers can still read a `Socket`'s `RawSocket` via
+/// `AsRawSocket`, but registering it is left to them.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Socket {
+    #[cfg(unix)]
+    fd: RawFd,
+    #[cfg(windows)]
+    socket: RawSocket,
+}
+
+impl Socket {
+    #[cfg(unix)]
+    pub(crate) fn new(fd: RawFd) -> Socket {
+        Socket { fd }
+    }
+
+    #[cfg(windows)]
+    pub(crate) fn new(socket: RawSocket) -> Socket {
+        Socket { socket }
+    }
+
+    /// Register this socket with a `mio::Poll`, with the given readiness
+    /// `interest`.
+    #[cfg(unix)]
+    pub fn register(&self, poll: &Poll, token: Token, interest: Ready) -> io::Result<()> {
+        poll.register(&EventedFd(&self.fd), token, interest, PollOpt::edge())
+    }
+
+    /// Re-register this socket with a `mio::Poll`, for example after its
+    /// readiness interest has changed.
+    #[cfg(unix)]
+    pub fn reregister(&self, poll: &Poll, token: Token, interest: Ready) -> io::Result<()> {
+        poll.reregister(&EventedFd(&self.fd), token, interest, PollOpt::edge())
+    }
+
+    /// Deregister this socket from a `mio::Poll`.
+    #[cfg(unix)]
+    pub fn deregister(&self, poll: &Poll) -> io::Result<()> {
+        poll.deregister(&EventedFd(&self.fd))
+    }
+}
+
+#[cfg(unix)]
+impl AsRawFd for Socket {
+    fn as_raw_fd(&self) -> RawFd {
+        self.fd
+    }
+}
+
+#[cfg(windows)]
+impl AsRawSocket for Socket {
+    fn as_raw_socket(&self) -> RawSocket {
+        self.socket
+    }
+}
+
+/// The readiness that `Channel` is interested in for a given `Socket`, as
+/// yielded alongside it by `get_socket_readiness()`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SocketReadiness {
+    socket: Socket,
+    readable: bool,
+    writable: bool,
+}
+
+impl SocketReadiness {
+    pub(crate) fn new(socket: Socket, readable: bool, writable: bool) -> SocketReadiness {
+        SocketReadiness {
+            socket,
+            readable,
+            writable,
+        }
+    }
+
+    /// The socket this readiness applies to.
+    pub fn socket(&self) -> Socket {
+        self.socket
+    }
+
+    /// Whether the channel wants to know when this socket becomes readable.
+    pub fn readable(&self) -> bool {
+        self.readable
+    }
+
+    /// Whether the channel wants to know when this socket becomes writable.
+    pub fn writable(&self) -> bool {
+        self.writable
+    }
+
+    /// The `mio::Ready` corresponding to this readiness, for registering
+    /// with a `mio::Poll`.
+    #[cfg(unix)]
+    pub fn interest(&self) -> Option<Ready> {
+        match (self.readable, self.writable) {
+            (true, true) => Some(Ready::readable() | Ready::writable()),
+            (true, false) => Some(Ready::readable()),
+            (false, true) => Some(Ready::writable()),
+            (false, false) => None,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+extern crate c_ares_sys;
+
+use std::os::raw::c_int;
+
+/// The maximum number of `AResult`s that `AResults::parse_from` will return.
+pub const MAX_ADDRTTLS: usize = 32;
+
+/// A value used in place of a real socket, when there is nothing for
+/// `Channel::process_fd()` to do on one side.
+pub const SOCKET_BAD: c_int = c_ares_sys::ARES_SOCKET_BAD;
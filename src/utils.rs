@@ -0,0 +1,46 @@
+extern crate c_ares_sys;
+
+use std::net::Ipv4Addr;
+use std::os::raw::c_int;
+
+use error::AresError;
+
+/// Convert a raw c-ares status code into an `AresError`.
+///
+/// Callers should only use this for values other than `ARES_SUCCESS`.
+pub fn ares_error(code: c_int) -> AresError {
+    match code {
+        c_ares_sys::ARES_ENODATA => AresError::ENODATA,
+        c_ares_sys::ARES_EFORMERR => AresError::EFORMERR,
+        c_ares_sys::ARES_ESERVFAIL => AresError::ESERVFAIL,
+        c_ares_sys::ARES_ENOTFOUND => AresError::ENOTFOUND,
+        c_ares_sys::ARES_ENOTIMP => AresError::ENOTIMP,
+        c_ares_sys::ARES_EREFUSED => AresError::EREFUSED,
+        c_ares_sys::ARES_EBADQUERY => AresError::EBADQUERY,
+        c_ares_sys::ARES_EBADNAME => AresError::EBADNAME,
+        c_ares_sys::ARES_EBADFAMILY => AresError::EBADFAMILY,
+        c_ares_sys::ARES_EBADRESP => AresError::EBADRESP,
+        c_ares_sys::ARES_ECONNREFUSED => AresError::ECONNREFUSED,
+        c_ares_sys::ARES_ETIMEOUT => AresError::ETIMEOUT,
+        c_ares_sys::ARES_EOF => AresError::EOF,
+        c_ares_sys::ARES_EFILE => AresError::EFILE,
+        c_ares_sys::ARES_ENOMEM => AresError::ENOMEM,
+        c_ares_sys::ARES_EDESTRUCTION => AresError::EDESTRUCTION,
+        c_ares_sys::ARES_EBADSTR => AresError::EBADSTR,
+        c_ares_sys::ARES_EBADFLAGS => AresError::EBADFLAGS,
+        c_ares_sys::ARES_ENONAME => AresError::ENONAME,
+        c_ares_sys::ARES_EBADHINTS => AresError::EBADHINTS,
+        c_ares_sys::ARES_ENOTINITIALIZED => AresError::ENOTINITIALIZED,
+        c_ares_sys::ARES_ELOADIPHLPAPI => AresError::ELOADIPHLPAPI,
+        c_ares_sys::ARES_EADDRGETNETWORKPARAMS => AresError::EADDRGETNETWORKPARAMS,
+        c_ares_sys::ARES_ECANCELLED => AresError::ECANCELLED,
+        c_ares_sys::ARES_ESERVICE => AresError::ESERVICE,
+        c_ares_sys::ARES_ENOSERVER => AresError::ENOSERVER,
+        other => AresError::Unknown(other),
+    }
+}
+
+/// Convert a c-ares `Struct_in_addr` into an `Ipv4Addr`.
+pub fn ipv4_from_in_addr(addr: &c_ares_sys::Struct_in_addr) -> Ipv4Addr {
+    Ipv4Addr::from(u32::from_be(addr.s_addr))
+}